@@ -0,0 +1,98 @@
+// ABOUTME: Command-line interface for the migrator binary
+// ABOUTME: Wires RemoteClient, DbCtx, and the notifier config together for the resume/list subcommands
+
+use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
+use std::sync::{Arc, Mutex};
+
+use crate::db::DbCtx;
+use crate::notifier::NotifierConfig;
+use crate::remote::client::RemoteClient;
+
+#[derive(Parser)]
+#[command(name = "migrator")]
+pub struct Cli {
+    /// Base URL of the remote execution API.
+    #[arg(long, default_value = "https://api.seren.dev")]
+    pub api_base_url: String,
+
+    /// Path to the local job-state database used for resume/list.
+    #[arg(long, default_value = "state.db")]
+    pub state_db: String,
+
+    /// Path to a notifier config file (see `NotifierConfig`), if alerts
+    /// should fire on job completion/failure.
+    #[arg(long)]
+    pub notifier_config: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Reattach to a job recorded in the local state database that hasn't
+    /// reached a terminal state yet.
+    Resume { job_id: String },
+    /// List every job recorded in the local state database.
+    List,
+}
+
+impl Cli {
+    fn open_client(&self) -> Result<(RemoteClient, Arc<Mutex<DbCtx>>)> {
+        let db = Arc::new(Mutex::new(
+            DbCtx::open(&self.state_db).context("Failed to open local state database")?,
+        ));
+
+        let mut client = RemoteClient::new(self.api_base_url.clone())?.with_db_ctx(db.clone());
+
+        if let Some(path) = &self.notifier_config {
+            let notifiers = NotifierConfig::load_from_file(path)?.build_notifiers();
+            client = client.with_notifiers(notifiers);
+        }
+
+        Ok((client, db))
+    }
+
+    pub async fn run(&self) -> Result<()> {
+        match &self.command {
+            Command::Resume { job_id } => self.run_resume(job_id).await,
+            Command::List => self.run_list(),
+        }
+    }
+
+    async fn run_resume(&self, job_id: &str) -> Result<()> {
+        let (client, _db) = self.open_client()?;
+
+        let status = client
+            .resume_job(job_id, |status| {
+                println!("{}: {}", status.job_id, status.status);
+            })
+            .await?;
+
+        println!("Final status: {}", status.status);
+
+        if let Some(error) = &status.error {
+            println!("Error: {}", error);
+            if error.suggests_local_fallback() {
+                println!("Hint: this looks like a connectivity problem — retry with --local");
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run_list(&self) -> Result<()> {
+        let (_client, db) = self.open_client()?;
+        let jobs = db.lock().unwrap().list_jobs()?;
+
+        for job in jobs {
+            println!(
+                "{}\t{}\t{}/{}",
+                job.job_id, job.status, job.databases_completed, job.databases_total
+            );
+        }
+
+        Ok(())
+    }
+}
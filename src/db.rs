@@ -0,0 +1,275 @@
+// ABOUTME: Persistent job state store backed by SQLite
+// ABOUTME: Records submitted jobs and their latest status so they can be resumed across process restarts
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::error::MigratorError;
+use crate::remote::models::{JobSpec, JobStatus, ProgressInfo};
+
+/// Durable record of a submitted job, as stored in `state.db`.
+#[derive(Debug, Clone)]
+pub struct JobRecord {
+    pub job_id: String,
+    pub spec: JobSpec,
+    pub status: String,
+    pub current_database: Option<String>,
+    pub databases_completed: usize,
+    pub databases_total: usize,
+    pub error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// `DbCtx` owns the local SQLite connection used to make migrations
+/// resumable: every submitted job is inserted once, and every polled
+/// status updates that job's row, so a crashed or
+/// disconnected CLI process can reattach to an in-flight job later.
+pub struct DbCtx {
+    conn: Connection,
+}
+
+impl DbCtx {
+    pub fn open(path: &str) -> Result<Self> {
+        let conn = Connection::open(path)
+            .with_context(|| format!("Failed to open job state database at {}", path))?;
+
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS jobs (
+                job_id              TEXT PRIMARY KEY,
+                spec_json           TEXT NOT NULL,
+                status              TEXT NOT NULL,
+                current_database    TEXT,
+                databases_completed INTEGER NOT NULL DEFAULT 0,
+                databases_total     INTEGER NOT NULL DEFAULT 0,
+                error               TEXT,
+                created_at          TEXT NOT NULL,
+                updated_at          TEXT NOT NULL
+            );",
+        )
+        .context("Failed to initialize job state schema")?;
+
+        Ok(Self { conn })
+    }
+
+    /// Record a freshly submitted job. Called once, right after `submit_job` returns.
+    pub fn insert_job(&self, job_id: &str, spec: &JobSpec, now: &str) -> Result<()> {
+        let spec_json = serde_json::to_string(spec).context("Failed to serialize job spec")?;
+
+        self.conn
+            .execute(
+                "INSERT INTO jobs (job_id, spec_json, status, databases_completed, databases_total, created_at, updated_at)
+                 VALUES (?1, ?2, 'submitted', 0, 0, ?3, ?3)",
+                params![job_id, spec_json, now],
+            )
+            .context("Failed to insert job record")?;
+
+        Ok(())
+    }
+
+    /// Update the stored row of a job recorded by [`DbCtx::insert_job`]
+    /// with its latest observed status. Called by `poll_until_complete` on
+    /// every tick. A job id with no row is left unrecorded: no row is inserted.
+    pub fn record_status(&self, job_id: &str, status: &JobStatus, now: &str) -> Result<()> {
+        let progress = status.progress.as_ref();
+        let current_database = progress.and_then(|p| p.current_database.clone());
+        let databases_completed = progress.map(|p| p.databases_completed).unwrap_or(0);
+        let databases_total = progress.map(|p| p.databases_total).unwrap_or(0);
+        let error = status
+            .error
+            .as_ref()
+            .map(serde_json::to_string)
+            .transpose()
+            .context("Failed to serialize job error")?;
+
+        self.conn
+            .execute(
+                "UPDATE jobs SET status = ?2, current_database = ?3, databases_completed = ?4,
+                    databases_total = ?5, error = ?6, updated_at = ?7
+                 WHERE job_id = ?1",
+                params![
+                    job_id,
+                    status.status,
+                    current_database,
+                    databases_completed,
+                    databases_total,
+                    error,
+                    now,
+                ],
+            )
+            .context("Failed to update job record")?;
+
+        Ok(())
+    }
+
+    pub fn get_job(&self, job_id: &str) -> Result<Option<JobRecord>> {
+        self.conn
+            .query_row(
+                "SELECT job_id, spec_json, status, current_database, databases_completed,
+                        databases_total, error, created_at, updated_at
+                 FROM jobs WHERE job_id = ?1",
+                params![job_id],
+                Self::row_to_record,
+            )
+            .optional()
+            .context("Failed to look up job record")
+    }
+
+    /// All jobs, most recently updated first. Backs the `migrator list` command.
+    pub fn list_jobs(&self) -> Result<Vec<JobRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT job_id, spec_json, status, current_database, databases_completed,
+                    databases_total, error, created_at, updated_at
+             FROM jobs ORDER BY updated_at DESC",
+        )?;
+
+        let rows = stmt
+            .query_map([], Self::row_to_record)
+            .context("Failed to list job records")?;
+
+        rows.collect::<rusqlite::Result<Vec<_>>>()
+            .context("Failed to read job records")
+    }
+
+    /// Jobs that have not reached a terminal state, i.e. candidates for
+    /// `migrator resume <job_id>` to reattach `poll_until_complete` to.
+    pub fn resumable_jobs(&self) -> Result<Vec<JobRecord>> {
+        Ok(self
+            .list_jobs()?
+            .into_iter()
+            .filter(|job| !matches!(job.status.as_str(), "completed" | "failed"))
+            .collect())
+    }
+
+    fn row_to_record(row: &rusqlite::Row) -> rusqlite::Result<JobRecord> {
+        let spec_json: String = row.get(1)?;
+        let spec: JobSpec = serde_json::from_str(&spec_json).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+        Ok(JobRecord {
+            job_id: row.get(0)?,
+            spec,
+            status: row.get(2)?,
+            current_database: row.get(3)?,
+            databases_completed: row.get::<_, i64>(4)? as usize,
+            databases_total: row.get::<_, i64>(5)? as usize,
+            error: row.get(6)?,
+            created_at: row.get(7)?,
+            updated_at: row.get(8)?,
+        })
+    }
+}
+
+impl JobRecord {
+    pub fn to_progress_info(&self) -> ProgressInfo {
+        ProgressInfo {
+            current_database: self.current_database.clone(),
+            databases_completed: self.databases_completed,
+            databases_total: self.databases_total,
+        }
+    }
+
+    /// Deserialize the stored error back into a `MigratorError`, if present.
+    pub fn error_detail(&self) -> Option<MigratorError> {
+        self.error.as_deref().and_then(|e| serde_json::from_str(e).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn sample_spec() -> JobSpec {
+        JobSpec {
+            version: "1".to_string(),
+            command: "sync".to_string(),
+            source_url: "postgres://source".to_string(),
+            target_url: "postgres://target".to_string(),
+            filter: None,
+            options: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_get_job_propagates_corrupt_spec_error() {
+        let db = DbCtx::open(":memory:").unwrap();
+        db.insert_job("job-1", &sample_spec(), "2026-01-01T00:00:00Z")
+            .unwrap();
+
+        db.conn
+            .execute(
+                "UPDATE jobs SET spec_json = 'not valid json' WHERE job_id = 'job-1'",
+                [],
+            )
+            .unwrap();
+
+        let result = db.get_job("job-1");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_insert_and_get_job() {
+        let db = DbCtx::open(":memory:").unwrap();
+        db.insert_job("job-1", &sample_spec(), "2026-01-01T00:00:00Z")
+            .unwrap();
+
+        let job = db.get_job("job-1").unwrap().expect("job should exist");
+        assert_eq!(job.status, "submitted");
+        assert_eq!(job.spec.command, "sync");
+    }
+
+    #[test]
+    fn test_record_status_updates_progress() {
+        let db = DbCtx::open(":memory:").unwrap();
+        db.insert_job("job-1", &sample_spec(), "2026-01-01T00:00:00Z")
+            .unwrap();
+
+        let status = JobStatus {
+            job_id: "job-1".to_string(),
+            status: "running".to_string(),
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            progress: Some(ProgressInfo {
+                current_database: Some("app_db".to_string()),
+                databases_completed: 1,
+                databases_total: 3,
+            }),
+            error: None,
+        };
+        db.record_status("job-1", &status, "2026-01-01T00:05:00Z")
+            .unwrap();
+
+        let job = db.get_job("job-1").unwrap().unwrap();
+        assert_eq!(job.status, "running");
+        assert_eq!(job.databases_completed, 1);
+        assert_eq!(job.current_database, Some("app_db".to_string()));
+    }
+
+    #[test]
+    fn test_resumable_jobs_excludes_terminal_states() {
+        let db = DbCtx::open(":memory:").unwrap();
+        db.insert_job("job-running", &sample_spec(), "2026-01-01T00:00:00Z")
+            .unwrap();
+        db.insert_job("job-done", &sample_spec(), "2026-01-01T00:00:00Z")
+            .unwrap();
+
+        let done_status = JobStatus {
+            job_id: "job-done".to_string(),
+            status: "completed".to_string(),
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            progress: None,
+            error: None,
+        };
+        db.record_status("job-done", &done_status, "2026-01-01T01:00:00Z")
+            .unwrap();
+
+        let resumable = db.resumable_jobs().unwrap();
+        assert_eq!(resumable.len(), 1);
+        assert_eq!(resumable[0].job_id, "job-running");
+    }
+}
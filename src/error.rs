@@ -1,25 +1,37 @@
 // ABOUTME: Custom error types for the migrator
 // ABOUTME: Provides context-specific error variants with actionable messages
 
-use std::fmt;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
-#[derive(Debug)]
+#[derive(Debug, Error, Clone, Serialize, Deserialize)]
 pub enum MigratorError {
+    #[error("Connection error: {0}")]
     Connection(String),
+
+    #[error("Permission error: {0}")]
     Permission(String),
+
+    #[error("Validation error: {0}")]
     Validation(String),
+
+    #[error("Migration error: {0}")]
     Migration(String),
+
+    #[error("Replication lag on subscription {subscription}: {lag_bytes} bytes behind")]
+    ReplicationLag { subscription: String, lag_bytes: u64 },
+
+    #[error("Subscription sync timed out")]
+    SubscriptionSyncTimeout,
+
+    #[error("Publication already exists")]
+    PublicationExists,
 }
 
-impl fmt::Display for MigratorError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            MigratorError::Connection(msg) => write!(f, "Connection error: {}", msg),
-            MigratorError::Permission(msg) => write!(f, "Permission error: {}", msg),
-            MigratorError::Validation(msg) => write!(f, "Validation error: {}", msg),
-            MigratorError::Migration(msg) => write!(f, "Migration error: {}", msg),
-        }
+impl MigratorError {
+    /// Whether this failure is a good candidate for suggesting `--local`
+    /// as a fallback, e.g. the remote service itself was unreachable.
+    pub fn suggests_local_fallback(&self) -> bool {
+        matches!(self, MigratorError::Connection(_))
     }
 }
-
-impl std::error::Error for MigratorError {}
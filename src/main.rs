@@ -0,0 +1,18 @@
+// ABOUTME: Binary entry point for the migrator CLI
+// ABOUTME: Parses arguments and dispatches to the cli module
+
+use anyhow::Result;
+use clap::Parser;
+
+mod cli;
+mod db;
+mod error;
+mod notifier;
+mod remote;
+mod replication;
+mod runner;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    cli::Cli::parse().run().await
+}
@@ -0,0 +1,217 @@
+// ABOUTME: Notification subsystem for terminal job status transitions
+// ABOUTME: Fires webhook or chat alerts when a job completes or fails
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::error::MigratorError;
+use crate::remote::models::{JobStatus, ProgressInfo};
+
+/// Configuration for the notifiers to fire when a job reaches a terminal
+/// state. Loaded from the JSON file given to the CLI's `--notifier-config` flag.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotifierConfig {
+    pub webhook_url: Option<String>,
+    pub chat_webhook_url: Option<String>,
+}
+
+impl NotifierConfig {
+    /// Load the notifier configuration from a JSON config file, e.g. the
+    /// file pointed to by the CLI's `--notifier-config` flag.
+    pub fn load_from_file(path: &str) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read notifier config at {}", path))?;
+
+        serde_json::from_str(&contents)
+            .with_context(|| format!("Failed to parse notifier config at {}", path))
+    }
+
+    /// Build the notifiers configured in this config, in the order they
+    /// should be fired.
+    pub fn build_notifiers(&self) -> Vec<Box<dyn Notifier>> {
+        let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+
+        if let Some(url) = &self.webhook_url {
+            notifiers.push(Box::new(WebhookNotifier::new(url.clone())));
+        }
+        if let Some(url) = &self.chat_webhook_url {
+            notifiers.push(Box::new(ChatNotifier::new(url.clone())));
+        }
+
+        notifiers
+    }
+}
+
+/// Payload delivered to notifiers on a terminal `JobStatus` transition.
+#[derive(Debug, Clone, Serialize)]
+pub struct JobCompletionEvent {
+    pub job_id: String,
+    pub status: String,
+    pub elapsed_seconds: Option<i64>,
+    pub progress: Option<ProgressInfo>,
+    pub error: Option<MigratorError>,
+}
+
+impl JobCompletionEvent {
+    /// Build an event from a terminal `JobStatus`, deriving elapsed time
+    /// from `created_at`/`completed_at` when both are present and parseable.
+    pub fn from_status(status: &JobStatus) -> Self {
+        let elapsed_seconds = status
+            .created_at
+            .as_deref()
+            .zip(status.completed_at.as_deref())
+            .and_then(|(created, completed)| {
+                let created = chrono::DateTime::parse_from_rfc3339(created).ok()?;
+                let completed = chrono::DateTime::parse_from_rfc3339(completed).ok()?;
+                Some((completed - created).num_seconds())
+            });
+
+        Self {
+            job_id: status.job_id.clone(),
+            status: status.status.clone(),
+            elapsed_seconds,
+            progress: status.progress.clone(),
+            error: status.error.clone(),
+        }
+    }
+}
+
+/// Fires when a job emitted by `poll_until_complete` reaches a terminal
+/// state (`completed` or `failed`), so operators get actionable alerts
+/// without watching the terminal.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, event: &JobCompletionEvent) -> Result<()>;
+}
+
+/// Posts the `JobCompletionEvent` built from the final `JobStatus`, as JSON,
+/// to a generic outbound webhook URL.
+pub struct WebhookNotifier {
+    client: Client,
+    url: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: Client::new(),
+            url,
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, event: &JobCompletionEvent) -> Result<()> {
+        self.client
+            .post(&self.url)
+            .json(event)
+            .send()
+            .await
+            .context("Failed to deliver webhook notification")?
+            .error_for_status()
+            .context("Webhook endpoint returned an error status")?;
+
+        Ok(())
+    }
+}
+
+/// Posts a one-line status summary to a chat endpoint (e.g. a Slack
+/// incoming webhook), in the `{"text": "..."}` shape most chat webhooks expect.
+pub struct ChatNotifier {
+    client: Client,
+    url: String,
+}
+
+impl ChatNotifier {
+    pub fn new(url: String) -> Self {
+        Self {
+            client: Client::new(),
+            url,
+        }
+    }
+
+    fn render(event: &JobCompletionEvent) -> String {
+        let elapsed = event
+            .elapsed_seconds
+            .map(|s| format!("{}s", s))
+            .unwrap_or_else(|| "unknown".to_string());
+
+        match &event.error {
+            Some(err) => format!(
+                "Migration job `{}` failed after {}: {}",
+                event.job_id, elapsed, err
+            ),
+            None => format!(
+                "Migration job `{}` completed after {}",
+                event.job_id, elapsed
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl Notifier for ChatNotifier {
+    async fn notify(&self, event: &JobCompletionEvent) -> Result<()> {
+        self.client
+            .post(&self.url)
+            .json(&serde_json::json!({ "text": Self::render(event) }))
+            .send()
+            .await
+            .context("Failed to deliver chat notification")?
+            .error_for_status()
+            .context("Chat endpoint returned an error status")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terminal_status() -> JobStatus {
+        JobStatus {
+            job_id: "job-1".to_string(),
+            status: "failed".to_string(),
+            created_at: Some("2026-01-01T00:00:00Z".to_string()),
+            started_at: None,
+            completed_at: Some("2026-01-01T00:05:30Z".to_string()),
+            progress: None,
+            error: Some(MigratorError::Connection("connection reset".to_string())),
+        }
+    }
+
+    #[test]
+    fn test_event_derives_elapsed_seconds() {
+        let event = JobCompletionEvent::from_status(&terminal_status());
+        assert_eq!(event.elapsed_seconds, Some(330));
+    }
+
+    #[test]
+    fn test_chat_notifier_renders_failure() {
+        let event = JobCompletionEvent::from_status(&terminal_status());
+        let text = ChatNotifier::render(&event);
+        assert!(text.contains("job-1"));
+        assert!(text.contains("connection reset"));
+    }
+
+    #[test]
+    fn test_config_builds_no_notifiers_by_default() {
+        let config = NotifierConfig::default();
+        assert!(config.build_notifiers().is_empty());
+    }
+
+    #[test]
+    fn test_load_from_file_builds_configured_notifiers() {
+        let path = std::env::temp_dir().join(format!("seren-notifier-config-{:?}.json", std::thread::current().id()));
+        std::fs::write(&path, r#"{"webhook_url": "https://hooks.example.com/seren"}"#).unwrap();
+
+        let config = NotifierConfig::load_from_file(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(config.build_notifiers().len(), 1);
+    }
+}
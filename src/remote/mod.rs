@@ -0,0 +1,8 @@
+// ABOUTME: Remote execution API module
+// ABOUTME: HTTP client and wire types for submitting and tracking jobs on the remote service
+
+pub mod client;
+pub mod models;
+
+pub use client::RemoteClient;
+pub use models::{FilterSpec, JobResponse, JobSpec, JobStatus, ProgressInfo};
@@ -4,6 +4,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::error::MigratorError;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JobSpec {
     pub version: String,
@@ -34,10 +36,10 @@ pub struct JobStatus {
     pub started_at: Option<String>,
     pub completed_at: Option<String>,
     pub progress: Option<ProgressInfo>,
-    pub error: Option<String>,
+    pub error: Option<MigratorError>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProgressInfo {
     pub current_database: Option<String>,
     pub databases_completed: usize,
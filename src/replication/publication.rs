@@ -0,0 +1,111 @@
+// ABOUTME: PostgreSQL publication management for logical replication
+// ABOUTME: Creates, lists, and drops PUBLICATIONs on the source database
+
+use anyhow::{Context, Result};
+use tokio_postgres::NoTls;
+
+/// The publication created for a given database, named so multiple jobs
+/// against the same source don't collide.
+pub(crate) fn publication_name(database: &str) -> String {
+    format!("seren_pub_{}", database)
+}
+
+/// Create a publication covering every table in `database` except those
+/// listed in `exclude_tables`.
+pub async fn create_publication(
+    source_url: &str,
+    database: &str,
+    exclude_tables: &[String],
+) -> Result<()> {
+    let (client, connection) = tokio_postgres::connect(source_url, NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to source {}", source_url))?;
+    tokio::spawn(connection);
+
+    let name = publication_name(database);
+
+    if exclude_tables.is_empty() {
+        client
+            .execute(&format!("CREATE PUBLICATION {} FOR ALL TABLES", name), &[])
+            .await
+            .with_context(|| format!("Failed to create publication {}", name))?;
+        return Ok(());
+    }
+
+    let tables = tables_excluding(&client, exclude_tables).await?;
+    client
+        .execute(
+            &format!("CREATE PUBLICATION {} FOR TABLE {}", name, tables.join(", ")),
+            &[],
+        )
+        .await
+        .with_context(|| format!("Failed to create publication {}", name))?;
+
+    Ok(())
+}
+
+async fn tables_excluding(
+    client: &tokio_postgres::Client,
+    exclude_tables: &[String],
+) -> Result<Vec<String>> {
+    let rows = client
+        .query(
+            "SELECT schemaname || '.' || tablename FROM pg_tables \
+             WHERE schemaname NOT IN ('pg_catalog', 'information_schema')",
+            &[],
+        )
+        .await
+        .context("Failed to list tables for publication")?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| row.get::<_, String>(0))
+        .filter(|table| {
+            !exclude_tables.iter().any(|excluded| {
+                table == excluded
+                    || table
+                        .strip_suffix(excluded.as_str())
+                        .map_or(false, |prefix| prefix.ends_with('.'))
+            })
+        })
+        .collect())
+}
+
+pub async fn list_publications(source_url: &str) -> Result<Vec<String>> {
+    let (client, connection) = tokio_postgres::connect(source_url, NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to source {}", source_url))?;
+    tokio::spawn(connection);
+
+    let rows = client
+        .query("SELECT pubname FROM pg_publication", &[])
+        .await
+        .context("Failed to list publications")?;
+
+    Ok(rows.into_iter().map(|row| row.get(0)).collect())
+}
+
+pub async fn drop_publication(source_url: &str, database: &str) -> Result<()> {
+    let (client, connection) = tokio_postgres::connect(source_url, NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to source {}", source_url))?;
+    tokio::spawn(connection);
+
+    let name = publication_name(database);
+    client
+        .execute(&format!("DROP PUBLICATION IF EXISTS {}", name), &[])
+        .await
+        .with_context(|| format!("Failed to drop publication {}", name))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publication_name_is_scoped_to_database() {
+        assert_eq!(publication_name("app_db"), "seren_pub_app_db");
+    }
+}
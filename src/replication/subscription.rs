@@ -0,0 +1,110 @@
+// ABOUTME: PostgreSQL subscription management for logical replication
+// ABOUTME: Creates subscriptions on the target database and waits for initial sync
+
+use anyhow::{Context, Result};
+use tokio_postgres::NoTls;
+
+use super::publication::publication_name;
+
+/// The subscription created for a given database, named so multiple jobs
+/// against the same target don't collide.
+pub(crate) fn subscription_name(database: &str) -> String {
+    format!("seren_sub_{}", database)
+}
+
+/// Create a subscription on `target_url` that replicates from the
+/// publication [`super::publication::create_publication`] created on `source_url`.
+pub async fn create_subscription(source_url: &str, target_url: &str, database: &str) -> Result<()> {
+    let (client, connection) = tokio_postgres::connect(target_url, NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to target {}", target_url))?;
+    tokio::spawn(connection);
+
+    let subscription = subscription_name(database);
+    let publication = publication_name(database);
+
+    client
+        .execute(
+            &format!(
+                "CREATE SUBSCRIPTION {} CONNECTION '{}' PUBLICATION {}",
+                subscription, source_url, publication
+            ),
+            &[],
+        )
+        .await
+        .with_context(|| format!("Failed to create subscription {}", subscription))?;
+
+    Ok(())
+}
+
+/// Poll `pg_subscription_rel` until every relation for `database`'s
+/// subscription has finished its initial table sync (state `r`, ready),
+/// or bail out after a reasonable number of attempts.
+pub async fn wait_for_sync(target_url: &str, database: &str) -> Result<()> {
+    let (client, connection) = tokio_postgres::connect(target_url, NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to target {}", target_url))?;
+    tokio::spawn(connection);
+
+    let subscription = subscription_name(database);
+
+    for _ in 0..60 {
+        let row = client
+            .query_one(
+                "SELECT COUNT(*) = 0 OR COUNT(*) FILTER (WHERE srsubstate != 'r') = 0 \
+                 FROM pg_subscription_rel sr \
+                 JOIN pg_subscription s ON s.oid = sr.srsubid \
+                 WHERE s.subname = $1",
+                &[&subscription],
+            )
+            .await
+            .with_context(|| format!("Failed to check sync state for {}", subscription))?;
+
+        if row.get::<_, bool>(0) {
+            return Ok(());
+        }
+
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+
+    anyhow::bail!("Timed out waiting for subscription {} to finish initial sync", subscription)
+}
+
+pub async fn list_subscriptions(target_url: &str) -> Result<Vec<String>> {
+    let (client, connection) = tokio_postgres::connect(target_url, NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to target {}", target_url))?;
+    tokio::spawn(connection);
+
+    let rows = client
+        .query("SELECT subname FROM pg_subscription", &[])
+        .await
+        .context("Failed to list subscriptions")?;
+
+    Ok(rows.into_iter().map(|row| row.get(0)).collect())
+}
+
+pub async fn drop_subscription(target_url: &str, database: &str) -> Result<()> {
+    let (client, connection) = tokio_postgres::connect(target_url, NoTls)
+        .await
+        .with_context(|| format!("Failed to connect to target {}", target_url))?;
+    tokio::spawn(connection);
+
+    let subscription = subscription_name(database);
+    client
+        .execute(&format!("DROP SUBSCRIPTION IF EXISTS {}", subscription), &[])
+        .await
+        .with_context(|| format!("Failed to drop subscription {}", subscription))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscription_name_is_scoped_to_database() {
+        assert_eq!(subscription_name("app_db"), "seren_sub_app_db");
+    }
+}
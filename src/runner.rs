@@ -0,0 +1,197 @@
+// ABOUTME: Local execution of a JobSpec against the replication module
+// ABOUTME: Gives the --local fallback a real implementation instead of just an error message
+
+use anyhow::Result;
+
+use crate::error::MigratorError;
+use crate::remote::models::{JobSpec, JobStatus, ProgressInfo};
+use crate::replication::{
+    create_publication, create_subscription, get_replication_lag, is_replication_caught_up,
+    wait_for_sync,
+};
+use crate::replication::subscription::subscription_name;
+
+/// Executes a `JobSpec` on the user's own machine using the `replication`
+/// module, emitting the same `ProgressInfo`/`JobStatus` shape through a
+/// callback as `RemoteClient::poll_until_complete` does, so the CLI
+/// progress UI is identical whether a job runs remotely or locally.
+pub struct LocalRunner;
+
+impl LocalRunner {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub async fn run(&self, spec: &JobSpec, callback: impl Fn(&JobStatus)) -> Result<JobStatus> {
+        let databases = spec
+            .filter
+            .as_ref()
+            .and_then(|f| f.include_databases.clone())
+            .unwrap_or_default();
+
+        if databases.is_empty() {
+            anyhow::bail!(
+                "Local runner requires at least one database in filter.include_databases"
+            );
+        }
+
+        let exclude_tables = spec
+            .filter
+            .as_ref()
+            .and_then(|f| f.exclude_tables.clone())
+            .unwrap_or_default();
+
+        let total = databases.len();
+        let mut status = self.emit(&spec.version, "running", None, 0, total);
+        callback(&status);
+
+        for (completed, database) in databases.iter().enumerate() {
+            status = self.emit(&spec.version, "running", Some(database.clone()), completed, total);
+            callback(&status);
+
+            if let Err(err) = self
+                .run_one(spec, database, &exclude_tables)
+                .await
+            {
+                status = self.emit_failed(&spec.version, err);
+                callback(&status);
+                return Ok(status);
+            }
+        }
+
+        status = self.emit(&spec.version, "completed", None, total, total);
+        callback(&status);
+        Ok(status)
+    }
+
+    async fn run_one(
+        &self,
+        spec: &JobSpec,
+        database: &str,
+        exclude_tables: &[String],
+    ) -> Result<(), MigratorError> {
+        create_publication(&spec.source_url, database, exclude_tables)
+            .await
+            .map_err(map_publication_error)?;
+
+        create_subscription(&spec.source_url, &spec.target_url, database)
+            .await
+            .map_err(|e| MigratorError::Migration(e.to_string()))?;
+
+        if spec.command == "sync" {
+            wait_for_sync(&spec.target_url, database)
+                .await
+                .map_err(|_| MigratorError::SubscriptionSyncTimeout)?;
+
+            let caught_up = is_replication_caught_up(&spec.source_url, database)
+                .await
+                .map_err(|e| MigratorError::Migration(e.to_string()))?;
+
+            if !caught_up {
+                let lag_bytes = get_replication_lag(&spec.source_url, database)
+                    .await
+                    .map_err(|e| MigratorError::Migration(e.to_string()))?;
+
+                return Err(MigratorError::ReplicationLag {
+                    subscription: subscription_name(database),
+                    lag_bytes,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn emit(
+        &self,
+        job_id: &str,
+        state: &str,
+        current_database: Option<String>,
+        completed: usize,
+        total: usize,
+    ) -> JobStatus {
+        JobStatus {
+            job_id: format!("local-{}", job_id),
+            status: state.to_string(),
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            progress: Some(ProgressInfo {
+                current_database,
+                databases_completed: completed,
+                databases_total: total,
+            }),
+            error: None,
+        }
+    }
+
+    fn emit_failed(&self, job_id: &str, error: MigratorError) -> JobStatus {
+        JobStatus {
+            job_id: format!("local-{}", job_id),
+            status: "failed".to_string(),
+            created_at: None,
+            started_at: None,
+            completed_at: None,
+            progress: None,
+            error: Some(error),
+        }
+    }
+}
+
+impl Default for LocalRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Distinguish "publication already exists" (Postgres `duplicate_object`,
+/// SQLSTATE 42710) from other publication failures, so `LocalRunner`
+/// surfaces the structured `MigratorError::PublicationExists` variant
+/// instead of flattening every failure into `Migration`.
+fn map_publication_error(err: anyhow::Error) -> MigratorError {
+    let is_duplicate = err
+        .chain()
+        .filter_map(|cause| cause.downcast_ref::<tokio_postgres::Error>())
+        .any(|e| e.code() == Some(&tokio_postgres::error::SqlState::DUPLICATE_OBJECT));
+
+    if is_duplicate {
+        MigratorError::PublicationExists
+    } else {
+        MigratorError::Migration(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::remote::models::FilterSpec;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_run_requires_at_least_one_database() {
+        let runner = LocalRunner::new();
+        let spec = JobSpec {
+            version: "1".to_string(),
+            command: "sync".to_string(),
+            source_url: "postgres://source".to_string(),
+            target_url: "postgres://target".to_string(),
+            filter: Some(FilterSpec {
+                include_databases: None,
+                exclude_tables: None,
+            }),
+            options: HashMap::new(),
+        };
+
+        let result = runner.run(&spec, |_| {}).await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_map_publication_error_falls_back_to_migration() {
+        let err = anyhow::anyhow!("connection refused");
+        assert!(matches!(
+            map_publication_error(err),
+            MigratorError::Migration(_)
+        ));
+    }
+}